@@ -1,21 +1,22 @@
+use std::collections::{HashMap, HashSet};
 
 trait Display {
     fn display(&self) -> String;
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Symbol {
-    text: &'static str,
+    text: String,
 }
 
 impl Symbol {
-    fn new(text: &'static str) -> Self {
+    fn new(text: &str) -> Self {
         if text.len() == 0 {
             panic!("Symbol text must be nonempty string.");
         }
 
         Symbol {
-            text
+            text: text.to_string()
         }
     }
 
@@ -24,7 +25,7 @@ impl Symbol {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Production {
     symbols: Vec<Symbol>,
 }
@@ -40,15 +41,18 @@ impl Production {
 impl Display for Production {
     fn display(&self) -> String {
         let mut result = String::new();
-        let symbs = self.symbols.iter();
-        for s in symbs {
-            result.push_str(s.text);
+        let num_symbols = self.symbols.len();
+        for (i, s) in self.symbols.iter().enumerate() {
+            result.push_str(&s.text);
+            if i < num_symbols - 1 {
+                result.push_str(" ");
+            }
         }
         return result;
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Rule {
     symbol: Symbol,
     derivations: Vec<Production>,
@@ -71,7 +75,7 @@ impl Display for Vec<Production> {
 }
 
 impl Rule {
-    fn new(symbol: &'static str, productions: Vec<Production>) -> Self {
+    fn new(symbol: &str, productions: Vec<Production>) -> Self {
         let lhs_symbol = Symbol::new(symbol);
         if lhs_symbol.is_terminal() {
             panic!("LHS symbol of rule must be non-terminal.");
@@ -87,10 +91,85 @@ impl Rule {
         }
     }
 
-    fn has_direct_left_recursion(&self) -> bool {
-        self.derivations.iter().fold(false, |acc, p| {
-            acc || (p.symbols.iter().nth(0).unwrap().text == self.symbol.text)
-        })
+}
+
+#[derive(Debug)]
+enum GrammarError {
+    UndefinedSymbol(String),
+}
+
+#[derive(Debug)]
+enum ParseError {
+    MissingAssignment(String),
+    InvalidLhs(String),
+    EmptyProduction(String),
+    EmptyInput,
+}
+
+// A position within a rule's production: `rule`/`production` pick out the
+// production, and `dot` is how many of its symbols have been consumed so
+// far (`dot == production.symbols.len()` is the position at its end).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct NfaState {
+    rule: usize,
+    production: usize,
+    dot: usize,
+}
+
+// An edge out of an `NfaState`. `label` is the symbol consumed to take this
+// edge, or `None` for an epsilon edge. `is_left_expansion` marks the epsilon
+// edges that left-expand a non-terminal at the dot into one of its own
+// rule's start positions, and the epsilon edges that skip a nullable symbol
+// at the dot.
+#[derive(Debug, Clone, Copy)]
+struct NfaTransition<'a> {
+    target: NfaState,
+    label: Option<&'a str>,
+    is_left_expansion: bool,
+}
+
+struct Nfa<'a> {
+    transitions: HashMap<NfaState, Vec<NfaTransition<'a>>>,
+}
+
+impl<'a> Nfa<'a> {
+    fn states(&self) -> impl Iterator<Item = &NfaState> {
+        self.transitions.keys()
+    }
+
+    fn transitions_from(&self, state: &NfaState) -> &[NfaTransition<'a>] {
+        self.transitions.get(state).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    // Demonstrates the reachability query this structure exists for: a
+    // grammar has left recursion iff some rule's start position can reach
+    // itself again by following only left-expansion edges.
+    fn has_left_expansion_cycle(&self) -> bool {
+        self.transitions.keys()
+            .filter(|s| s.dot == 0)
+            .any(|&start| self.reaches_via_left_expansion(start, start, &mut HashSet::new()))
+    }
+
+    fn reaches_via_left_expansion(&self, start: NfaState, current: NfaState, visited: &mut HashSet<NfaState>) -> bool {
+        for transition in self.transitions_from(&current) {
+            if !transition.is_left_expansion {
+                continue;
+            }
+
+            if transition.target == start {
+                return true;
+            }
+
+            if !visited.insert(transition.target) {
+                continue;
+            }
+
+            if self.reaches_via_left_expansion(start, transition.target, visited) {
+                return true;
+            }
+        }
+
+        false
     }
 }
 
@@ -105,50 +184,690 @@ impl Grammar {
         }
     }
 
-    fn derives_to_symbol_helper(&self, start: &Symbol, target: &Symbol) -> bool {
-        if start.text == target.text {
-            return true;
+    // Parses the textual shape `display()` prints: one rule per line,
+    // `<LHS> := production or production or ...`, with symbols separated
+    // by whitespace and `<...>` tokens treated as non-terminals.
+    fn parse(input: &str) -> Result<Grammar, ParseError> {
+        let mut rules = Vec::new();
+
+        for line in input.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (lhs, rhs) = line
+                .split_once(":=")
+                .ok_or_else(|| ParseError::MissingAssignment(line.to_string()))?;
+
+            let lhs = lhs.trim();
+            let lhs_symbol = Symbol::new(lhs);
+            if lhs_symbol.is_terminal() {
+                return Err(ParseError::InvalidLhs(line.to_string()));
+            }
+
+            let mut productions = Vec::new();
+            for alternative in rhs.split(" or ") {
+                let symbols: Vec<Symbol> = alternative
+                    .split_whitespace()
+                    .map(Symbol::new)
+                    .collect();
+
+                if symbols.is_empty() {
+                    return Err(ParseError::EmptyProduction(line.to_string()));
+                }
+
+                productions.push(Production::new(symbols));
+            }
+
+            rules.push(Rule::new(lhs, productions));
+        }
+
+        if rules.is_empty() {
+            return Err(ParseError::EmptyInput);
+        }
+
+        Ok(Grammar::new(rules))
+    }
+
+    // A non-terminal is nullable if some production of its rule consists
+    // entirely of nullable symbols, where the terminal `EmptyString` is
+    // treated as nullable. Computed by fixpoint since nullability of one
+    // non-terminal can depend on another's.
+    fn compute_nullable(&self) -> HashSet<&str> {
+        let mut nullable: HashSet<&str> = HashSet::new();
+
+        loop {
+            let mut changed = false;
+
+            for rule in self.rules.iter() {
+                if nullable.contains(rule.symbol.text.as_str()) {
+                    continue;
+                }
+
+                let is_nullable = rule.derivations.iter().any(|production| {
+                    production.symbols.iter().all(|s| Self::symbol_is_nullable(s, &nullable))
+                });
+
+                if is_nullable {
+                    nullable.insert(rule.symbol.text.as_str());
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
         }
 
-        return self.derives_to_symbol(start, target);
+        nullable
     }
 
-    fn derives_to_symbol(&self, start: &Symbol, target: &Symbol) -> bool {
-        if start.is_terminal() {
+    fn symbol_is_nullable(symbol: &Symbol, nullable: &HashSet<&str>) -> bool {
+        if symbol.text == "EmptyString" {
+            return true;
+        }
+
+        if symbol.is_terminal() {
             return false;
         }
 
-        // Find start symbol in rules
-        let starting_rule = self.rules.iter().find(|rule| {
-            rule.symbol.text == start.text
-        });
+        nullable.contains(symbol.text.as_str())
+    }
 
-        let starting_rule = starting_rule.expect("No matching LHS symbol.");
+    const END_OF_INPUT: &'static str = "$";
 
-        return starting_rule.derivations.iter().fold(false, |acc, production| {
-            return acc || self.derives_to_symbol_helper(production.symbols.iter().nth(0).unwrap(), target);
-        });
+    fn first_of_symbol<'a>(symbol: &'a Symbol, first: &HashMap<&'a str, HashSet<&'a str>>) -> HashSet<&'a str> {
+        if symbol.text == "EmptyString" {
+            return HashSet::from(["EmptyString"]);
+        }
+
+        if symbol.is_terminal() {
+            return HashSet::from([symbol.text.as_str()]);
+        }
+
+        first.get(symbol.text.as_str()).cloned().unwrap_or_default()
+    }
+
+    // FIRST of a whole symbol sequence: the union of FIRST(X1), and FIRST(X2)
+    // if X1 is nullable, and so on, adding the `EmptyString` marker only if
+    // every symbol in the sequence is nullable.
+    fn first_of_sequence<'a>(
+        symbols: &'a [Symbol],
+        nullable: &HashSet<&str>,
+        first: &HashMap<&'a str, HashSet<&'a str>>,
+    ) -> HashSet<&'a str> {
+        let mut result: HashSet<&str> = HashSet::new();
+        let mut all_nullable = true;
+
+        for symbol in symbols.iter() {
+            result.extend(Self::first_of_symbol(symbol, first).into_iter().filter(|&t| t != "EmptyString"));
+
+            if !Self::symbol_is_nullable(symbol, nullable) {
+                all_nullable = false;
+                break;
+            }
+        }
+
+        if all_nullable {
+            result.insert("EmptyString");
+        }
+
+        result
     }
 
-    fn has_indirect_left_recursion(&self) -> bool {
-        self.rules.iter().fold(false, |acc, r| {
-            return acc || self.derives_to_symbol(&r.symbol, &r.symbol);
-        })
+    // FIRST(A) by fixpoint: FIRST(A) includes FIRST(X1)\{EmptyString}, and
+    // continues into X2 only while X1 is nullable, adding `EmptyString`
+    // itself only if the whole right-hand side is nullable.
+    fn first_sets(&self) -> Result<HashMap<&str, HashSet<&str>>, GrammarError> {
+        self.validate_symbols()?;
+
+        let nullable = self.compute_nullable();
+        let mut first: HashMap<&str, HashSet<&str>> = self.rules.iter()
+            .map(|r| (r.symbol.text.as_str(), HashSet::new()))
+            .collect();
+
+        loop {
+            let mut changed = false;
+
+            for rule in self.rules.iter() {
+                for production in rule.derivations.iter() {
+                    let additions = Self::first_of_sequence(&production.symbols, &nullable, &first);
+                    let entry = first.get_mut(rule.symbol.text.as_str()).unwrap();
+
+                    for t in additions {
+                        if entry.insert(t) {
+                            changed = true;
+                        }
+                    }
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        Ok(first)
     }
 
-    fn has_left_recursion(&self) -> bool {
-        let has_direct_left = self.rules.iter().fold(false, |acc, r| {
-            acc || r.has_direct_left_recursion()
+    // FOLLOW by fixpoint, seeded by placing the end-of-input marker in
+    // FOLLOW of the start symbol (the first declared rule). For a production
+    // `A -> alpha B beta`, add FIRST(beta)\{EmptyString} to FOLLOW(B), and
+    // if beta is nullable also add FOLLOW(A) to FOLLOW(B).
+    fn follow_sets(&self) -> Result<HashMap<&str, HashSet<&str>>, GrammarError> {
+        self.validate_symbols()?;
+
+        let nullable = self.compute_nullable();
+        let first = self.first_sets()?;
+
+        let mut follow: HashMap<&str, HashSet<&str>> = self.rules.iter()
+            .map(|r| (r.symbol.text.as_str(), HashSet::new()))
+            .collect();
+
+        if let Some(start) = self.rules.first() {
+            follow.get_mut(start.symbol.text.as_str()).unwrap().insert(Self::END_OF_INPUT);
+        }
+
+        loop {
+            let mut changed = false;
+
+            for rule in self.rules.iter() {
+                for production in rule.derivations.iter() {
+                    for (i, symbol) in production.symbols.iter().enumerate() {
+                        if symbol.is_terminal() {
+                            continue;
+                        }
+
+                        let beta = &production.symbols[i + 1..];
+                        let beta_first = Self::first_of_sequence(beta, &nullable, &first);
+                        let beta_nullable = beta_first.contains("EmptyString");
+
+                        let mut additions: HashSet<&str> = beta_first
+                            .into_iter()
+                            .filter(|&t| t != "EmptyString")
+                            .collect();
+
+                        if beta_nullable {
+                            if let Some(follow_a) = follow.get(rule.symbol.text.as_str()) {
+                                additions.extend(follow_a.iter().copied());
+                            }
+                        }
+
+                        let entry = follow.get_mut(symbol.text.as_str()).unwrap();
+                        for t in additions {
+                            if entry.insert(t) {
+                                changed = true;
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        Ok(follow)
+    }
+
+    // Returns true when no rule has two alternatives whose FIRST sets
+    // overlap; an overlap would make a predictive (LL(1)) parser ambiguous
+    // about which alternative to expand. Only FIRST sets are compared;
+    // FOLLOW sets are not consulted.
+    fn is_ll1(&self) -> Result<bool, GrammarError> {
+        self.validate_symbols()?;
+
+        let nullable = self.compute_nullable();
+        let first = self.first_sets()?;
+
+        Ok(self.rules.iter().all(|rule| {
+            let production_firsts: Vec<HashSet<&str>> = rule.derivations.iter()
+                .map(|p| Self::first_of_sequence(&p.symbols, &nullable, &first))
+                .collect();
+
+            production_firsts.iter().enumerate().all(|(i, a)| {
+                production_firsts.iter().skip(i + 1).all(|b| a.is_disjoint(b))
+            })
+        }))
+    }
+
+    // Compiles the grammar into a left-linear automaton whose states are
+    // rule positions and whose edges are labeled by the symbol consumed,
+    // with epsilon edges that left-expand a non-terminal at the dot into
+    // the start positions of its own rules, plus epsilon edges that skip
+    // a nullable symbol at the dot and advance to the next one, mirroring
+    // how `leftmost_reaches` walks through a nullable prefix.
+    fn to_nfa(&self) -> Nfa<'_> {
+        let nullable = self.compute_nullable();
+        let mut transitions: HashMap<NfaState, Vec<NfaTransition>> = HashMap::new();
+
+        for (rule_idx, rule) in self.rules.iter().enumerate() {
+            for (production_idx, production) in rule.derivations.iter().enumerate() {
+                for dot in 0..=production.symbols.len() {
+                    transitions.entry(NfaState { rule: rule_idx, production: production_idx, dot }).or_insert_with(Vec::new);
+                }
+            }
+        }
+
+        for (rule_idx, rule) in self.rules.iter().enumerate() {
+            for (production_idx, production) in rule.derivations.iter().enumerate() {
+                for (dot, symbol) in production.symbols.iter().enumerate() {
+                    let state = NfaState { rule: rule_idx, production: production_idx, dot };
+
+                    transitions.get_mut(&state).unwrap().push(NfaTransition {
+                        target: NfaState { rule: rule_idx, production: production_idx, dot: dot + 1 },
+                        label: Some(symbol.text.as_str()),
+                        is_left_expansion: false,
+                    });
+
+                    if Self::symbol_is_nullable(symbol, &nullable) {
+                        transitions.get_mut(&state).unwrap().push(NfaTransition {
+                            target: NfaState { rule: rule_idx, production: production_idx, dot: dot + 1 },
+                            label: None,
+                            is_left_expansion: true,
+                        });
+                    }
+
+                    if symbol.is_terminal() {
+                        continue;
+                    }
+
+                    let expanded_rule = self.rules.iter().enumerate().find(|(_, r)| r.symbol.text == symbol.text);
+
+                    if let Some((target_rule_idx, target_rule)) = expanded_rule {
+                        for target_production_idx in 0..target_rule.derivations.len() {
+                            transitions.get_mut(&state).unwrap().push(NfaTransition {
+                                target: NfaState { rule: target_rule_idx, production: target_production_idx, dot: 0 },
+                                label: None,
+                                is_left_expansion: true,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        Nfa { transitions }
+    }
+
+    // For each rule `A`, the set of symbols reachable by walking a
+    // production left-to-right through a (possibly empty) run of nullable
+    // symbols. The grammar has left recursion iff some non-terminal reaches
+    // itself in the transitive closure of this relation.
+    fn leftmost_reaches(&self, nullable: &HashSet<&str>) -> HashMap<&str, HashSet<&str>> {
+        let mut edges: HashMap<&str, HashSet<&str>> = HashMap::new();
+
+        for rule in self.rules.iter() {
+            let reachable = edges.entry(rule.symbol.text.as_str()).or_insert_with(HashSet::new);
+
+            for production in rule.derivations.iter() {
+                for symbol in production.symbols.iter() {
+                    reachable.insert(symbol.text.as_str());
+
+                    if !Self::symbol_is_nullable(symbol, nullable) {
+                        break;
+                    }
+                }
+            }
+        }
+
+        edges
+    }
+
+    // Every non-terminal mentioned anywhere in the grammar must also appear
+    // as the LHS of some rule, or there is nothing to expand it into.
+    fn validate_symbols(&self) -> Result<(), GrammarError> {
+        let defined: HashSet<&str> = self.rules.iter().map(|r| r.symbol.text.as_str()).collect();
+
+        for rule in self.rules.iter() {
+            for production in rule.derivations.iter() {
+                for symbol in production.symbols.iter() {
+                    if !symbol.is_terminal() && !defined.contains(symbol.text.as_str()) {
+                        return Err(GrammarError::UndefinedSymbol(symbol.text.clone()));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Explicit DFS over the leftmost-reaches graph, starting from `start`.
+    // `visited` guards against the cycles we are looking for so the walk
+    // always terminates - recursion depth is bounded by the number of
+    // distinct non-terminals in `edges`, i.e. `self.rules.len()` for the
+    // caller's grammar. That in turn stays bounded after
+    // `eliminate_left_recursion`: it memoizes its forced-non-empty variant
+    // rules (chunk0-3) instead of iterating elimination on newly introduced
+    // tail rules, so it adds at most one tail rule per original rule plus
+    // one variant rule per distinct nullable non-terminal, never an
+    // unbounded chain.
+    fn find_cycle<'a>(
+        edges: &HashMap<&'a str, HashSet<&'a str>>,
+        start: &'a str,
+        current: &'a str,
+        visited: &mut HashSet<&'a str>,
+        path: &mut Vec<&'a str>,
+    ) -> Option<Vec<&'a str>> {
+        let neighbors = match edges.get(current) {
+            Some(neighbors) => neighbors,
+            None => return None,
+        };
+
+        for &next in neighbors.iter() {
+            if next == start {
+                return Some(path.clone());
+            }
+
+            if visited.contains(next) {
+                continue;
+            }
+
+            visited.insert(next);
+            path.push(next);
+
+            if let Some(cycle) = Self::find_cycle(edges, start, next, visited, path) {
+                return Some(cycle);
+            }
+
+            path.pop();
+        }
+
+        None
+    }
+
+    fn has_left_recursion(&self) -> Result<Option<Vec<&str>>, GrammarError> {
+        self.validate_symbols()?;
+
+        let nullable = self.compute_nullable();
+        let edges = self.leftmost_reaches(&nullable);
+
+        for rule in self.rules.iter() {
+            let start = rule.symbol.text.as_str();
+            let mut visited: HashSet<&str> = HashSet::new();
+            let mut path: Vec<&str> = vec![start];
+
+            if let Some(cycle) = Self::find_cycle(&edges, start, start, &mut visited, &mut path) {
+                return Ok(Some(cycle));
+            }
+        }
+
+        Ok(None)
+    }
+
+    // Concatenates `prefix` and `suffix` into a production's symbol list,
+    // dropping a lone `EmptyString` placeholder from either side rather
+    // than splicing it into the middle of real symbols, and reintroducing
+    // it only if the combined result would otherwise be empty.
+    fn concat_dropping_empty_string(prefix: &[Symbol], suffix: &[Symbol]) -> Vec<Symbol> {
+        let mut result: Vec<Symbol> = Vec::new();
+
+        if !(prefix.len() == 1 && prefix[0].text == "EmptyString") {
+            result.extend(prefix.iter().cloned());
+        }
+
+        if !(suffix.len() == 1 && suffix[0].text == "EmptyString") {
+            result.extend(suffix.iter().cloned());
+        }
+
+        if result.is_empty() {
+            result.push(Symbol::new("EmptyString"));
+        }
+
+        result
+    }
+
+    fn tail_symbol_for(symbol: &Symbol) -> Symbol {
+        let text = if symbol.text.ends_with('>') {
+            format!("{}_TAIL>", &symbol.text[..symbol.text.len() - 1])
+        } else {
+            format!("{}_TAIL", symbol.text)
+        };
+
+        Symbol::new(&text)
+    }
+
+    fn nonempty_symbol_for(symbol: &Symbol) -> Symbol {
+        let text = if symbol.text.ends_with('>') {
+            format!("{}_NONEMPTY>", &symbol.text[..symbol.text.len() - 1])
+        } else {
+            format!("{}_NONEMPTY", symbol.text)
+        };
+
+        Symbol::new(&text)
+    }
+
+    // Builds (and memoizes in `cache`, keyed by the original symbol's text)
+    // a variant of a nullable non-terminal's rule that can never derive the
+    // empty string: its `EmptyString` alternative is dropped, and any other
+    // alternative that is itself fully nullable is replaced by one copy per
+    // non-terminal position with that position forced non-empty in turn, so the
+    // union of copies still accepts everything the original alternative
+    // did except the all-empty derivation. Terminals and already
+    // non-nullable non-terminals are returned unchanged.
+    fn force_nonempty(
+        rules: &mut Vec<Rule>,
+        cache: &mut HashMap<String, Symbol>,
+        nullable: &HashSet<&str>,
+        symbol: &Symbol,
+    ) -> Symbol {
+        if symbol.is_terminal() || !Self::symbol_is_nullable(symbol, nullable) {
+            return symbol.clone();
+        }
+
+        if let Some(existing) = cache.get(&symbol.text) {
+            return existing.clone();
+        }
+
+        let nonempty_symbol = Self::nonempty_symbol_for(symbol);
+        cache.insert(symbol.text.clone(), nonempty_symbol.clone());
+
+        let rule_idx = rules
+            .iter()
+            .position(|r| r.symbol.text == symbol.text)
+            .expect("nullable non-terminal must have a rule");
+        let productions = rules[rule_idx].derivations.clone();
+
+        let mut forced: Vec<Production> = Vec::new();
+        for p in &productions {
+            if p.symbols.len() == 1 && p.symbols[0].text == "EmptyString" {
+                continue;
+            }
+
+            if !p.symbols.iter().all(|s| Self::symbol_is_nullable(s, nullable)) {
+                forced.push(p.clone());
+                continue;
+            }
+
+            for i in 0..p.symbols.len() {
+                if p.symbols[i].is_terminal() {
+                    continue;
+                }
+
+                let mut forced_symbols = p.symbols.clone();
+                forced_symbols[i] = Self::force_nonempty(rules, cache, nullable, &p.symbols[i]);
+                forced.push(Production::new(forced_symbols));
+            }
+        }
+
+        rules.push(Rule {
+            symbol: nonempty_symbol.clone(),
+            derivations: forced,
         });
 
-        if has_direct_left {
-            return true;
+        nonempty_symbol
+    }
+
+    // Rewrites `symbols` into one or more alternative symbol sequences that
+    // are guaranteed not to be fully nullable, if `symbols` as a whole
+    // isn't guaranteed already. One alternative is produced per non-terminal
+    // position, forcing that position non-empty via `force_nonempty`; their
+    // union still covers every string the original sequence covered, minus
+    // the purely-empty derivation, so an empty sequence or one made only of
+    // `EmptyString` yields no alternatives. This is what keeps a right-recursive tail
+    // rule built from `symbols` from being left-recursive on itself again.
+    fn force_nonempty_sequence(
+        rules: &mut Vec<Rule>,
+        cache: &mut HashMap<String, Symbol>,
+        nullable: &HashSet<&str>,
+        symbols: &[Symbol],
+    ) -> Vec<Vec<Symbol>> {
+        if !symbols.iter().all(|s| Self::symbol_is_nullable(s, nullable)) {
+            return vec![symbols.to_vec()];
         }
 
-        return self.has_indirect_left_recursion();
+        (0..symbols.len())
+            .filter(|&i| !symbols[i].is_terminal())
+            .map(|i| {
+                let mut forced = symbols.to_vec();
+                forced[i] = Self::force_nonempty(rules, cache, nullable, &symbols[i]);
+                forced
+            })
+            .collect()
     }
 
+    // Finds the position of `target_text` in `symbols`, if it is reachable
+    // by walking a nullable-only prefix from the front, mirroring
+    // `leftmost_reaches` rather than comparing only the literal first symbol.
+    fn leftmost_occurrence(symbols: &[Symbol], target_text: &str, nullable: &HashSet<&str>) -> Option<usize> {
+        for (idx, symbol) in symbols.iter().enumerate() {
+            if symbol.text == target_text {
+                return Some(idx);
+            }
+
+            if !Self::symbol_is_nullable(symbol, nullable) {
+                return None;
+            }
+        }
+
+        None
+    }
+
+    // Eliminates direct left recursion in `rules[i]` by splitting its
+    // productions into the left-recursive ones (`Ai -> alpha Ai beta`, where
+    // `Ai` is reachable through a nullable prefix `alpha`) and the rest
+    // (`Ai -> beta_m`), then rewriting to `Ai -> beta_m Ai'` plus a fresh
+    // right-recursive rule `Ai' -> alpha_k Ai' | EmptyString`. Whenever
+    // `alpha_k` is itself fully nullable, `Ai'` would immediately be
+    // left-recursive on itself again (the exact same nullable-mediated
+    // recursion `Ai` had), so `alpha_k` is first run through
+    // `force_nonempty_sequence` to guarantee it can't vanish entirely.
+    fn eliminate_direct_left_recursion(
+        rules: &mut Vec<Rule>,
+        i: usize,
+        nullable: &HashSet<&str>,
+        nonempty_cache: &mut HashMap<String, Symbol>,
+    ) {
+        let symbol_text = rules[i].symbol.text.clone();
+        let productions: Vec<Production> = rules[i].derivations.drain(..).collect();
+
+        let mut left_recursive: Vec<Production> = Vec::new();
+        let mut rest: Vec<Production> = Vec::new();
+
+        for p in productions {
+            match Self::leftmost_occurrence(&p.symbols, &symbol_text, nullable) {
+                Some(pos) => {
+                    let mut remainder = p.symbols[..pos].to_vec();
+                    remainder.extend(p.symbols[pos + 1..].iter().cloned());
+
+                    let alternatives =
+                        Self::force_nonempty_sequence(rules, nonempty_cache, nullable, &remainder);
+                    for alternative in alternatives {
+                        left_recursive.push(Production::new(alternative));
+                    }
+                }
+                None => rest.push(p),
+            }
+        }
+
+        if left_recursive.is_empty() {
+            rules[i].derivations = rest;
+            return;
+        }
+
+        let tail_symbol = Self::tail_symbol_for(&rules[i].symbol);
+        let tail_ref = std::slice::from_ref(&tail_symbol);
+
+        rules[i].derivations = rest
+            .iter()
+            .map(|p| Production::new(Self::concat_dropping_empty_string(&p.symbols, tail_ref)))
+            .collect();
 
+        let mut tail_derivations: Vec<Production> = left_recursive
+            .iter()
+            .map(|p| Production::new(Self::concat_dropping_empty_string(&p.symbols, tail_ref)))
+            .collect();
+        tail_derivations.push(Production::new(vec![Symbol::new("EmptyString")]));
+
+        rules.push(Rule {
+            symbol: tail_symbol,
+            derivations: tail_derivations,
+        });
+    }
+
+    // Rewrites `Ai`'s productions that reach an earlier `Aj` through a
+    // nullable prefix `alpha` (`Ai -> alpha Aj gamma`) by splicing in each of
+    // `Aj`'s productions in its place (`Ai -> alpha delta gamma`), per the
+    // substitution step of Paull's algorithm.
+    fn substitute_earlier_rule(rules: &mut Vec<Rule>, i: usize, j: usize, nullable: &HashSet<&str>) {
+        let aj_symbol_text = rules[j].symbol.text.clone();
+        let aj_derivations = rules[j].derivations.clone();
+
+        let expanded: Vec<Production> = rules[i]
+            .derivations
+            .drain(..)
+            .flat_map(|p| {
+                match Self::leftmost_occurrence(&p.symbols, &aj_symbol_text, nullable) {
+                    Some(pos) => {
+                        let prefix = p.symbols[..pos].to_vec();
+                        let gamma = p.symbols[pos + 1..].to_vec();
+
+                        aj_derivations
+                            .iter()
+                            .map(|delta| {
+                                let delta_gamma = Self::concat_dropping_empty_string(&delta.symbols, &gamma);
+                                Production::new(Self::concat_dropping_empty_string(&prefix, &delta_gamma))
+                            })
+                            .collect::<Vec<_>>()
+                    }
+                    None => vec![p],
+                }
+            })
+            .collect();
+
+        rules[i].derivations = expanded;
+    }
+
+    // Removes left recursion from the grammar via Paull's algorithm: fix an
+    // ordering of the non-terminals (the order the rules were declared in),
+    // then for each `Ai` substitute away references to earlier `Aj`s before
+    // eliminating any direct left recursion left in `Ai` itself. Reachability
+    // through a nullable prefix is treated the same as a literal leading
+    // symbol, matching the nullable-aware definition of left recursion from
+    // `has_left_recursion`. `nonempty_cache` is shared across every rule so
+    // each nullable non-terminal gets at most one forced-non-empty variant
+    // rule for the whole pass, however many tail rules end up needing it -
+    // elimination therefore adds at most one rule per original rule plus one
+    // per distinct nullable non-terminal actually forced, never an unbounded
+    // or ever-growing chain of tail rules.
+    fn eliminate_left_recursion(&self) -> Grammar {
+        let nullable = self.compute_nullable();
+        let mut rules: Vec<Rule> = self.rules.clone();
+        let mut nonempty_cache: HashMap<String, Symbol> = HashMap::new();
+        let n = rules.len();
+
+        for i in 0..n {
+            for j in 0..i {
+                Self::substitute_earlier_rule(&mut rules, i, j, &nullable);
+            }
+
+            Self::eliminate_direct_left_recursion(&mut rules, i, &nullable, &mut nonempty_cache);
+        }
+
+        Grammar::new(rules)
+    }
 }
 
 impl Display for Grammar {
@@ -164,7 +883,7 @@ impl Display for Grammar {
 
         for rule in self.rules.iter() {
             let suffix_spaces_count: usize = (longest - rule.symbol.text.len()) + 1;
-            result.push_str(rule.symbol.text);
+            result.push_str(&rule.symbol.text);
             result.push_str(&" ".repeat(suffix_spaces_count));
             result.push_str(":= ");
             result.push_str(&rule.derivations.display());
@@ -249,5 +968,110 @@ fn main() {
     ]);
 
     println!("{}", g.display());
-    println!("Has left recursion? {}", g.has_left_recursion());
+
+    match g.has_left_recursion() {
+        Ok(Some(cycle)) => println!("Has left recursion, cycle: {:?}", cycle),
+        Ok(None) => println!("Has left recursion? false"),
+        Err(e) => println!("Could not check for left recursion: {:?}", e),
+    }
+
+    let eliminated = g.eliminate_left_recursion();
+    println!("\nWith left recursion eliminated:\n{}", eliminated.display());
+
+    match eliminated.has_left_recursion() {
+        Ok(Some(cycle)) => println!("Has left recursion, cycle: {:?}", cycle),
+        Ok(None) => println!("Has left recursion? false"),
+        Err(e) => println!("Could not check for left recursion: {:?}", e),
+    }
+
+    match Grammar::parse(&g.display()) {
+        Ok(reparsed) => println!("\nRound-tripped through parse():\n{}", reparsed.display()),
+        Err(e) => println!("Failed to parse grammar text: {:?}", e),
+    }
+
+    match g.first_sets() {
+        Ok(first) => println!("\nFIRST sets: {:?}", first),
+        Err(e) => println!("\nCould not compute FIRST sets: {:?}", e),
+    }
+
+    match g.follow_sets() {
+        Ok(follow) => println!("FOLLOW sets: {:?}", follow),
+        Err(e) => println!("Could not compute FOLLOW sets: {:?}", e),
+    }
+
+    match g.is_ll1() {
+        Ok(is_ll1) => println!("Is LL(1)? {}", is_ll1),
+        Err(e) => println!("Could not check LL(1): {:?}", e),
+    }
+
+    let nfa = g.to_nfa();
+    println!("\nNFA has {} rule positions", nfa.states().count());
+    println!("Has left-expansion cycle? {}", nfa.has_left_expansion_cycle());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eliminate_left_recursion_removes_direct_recursion() {
+        let g = Grammar::parse("<E> := <E> + <T> or <T>\n<T> := C\n").unwrap();
+        assert!(g.has_left_recursion().unwrap().is_some());
+
+        let eliminated = g.eliminate_left_recursion();
+        assert_eq!(eliminated.has_left_recursion().unwrap(), None);
+    }
+
+    #[test]
+    fn eliminate_left_recursion_removes_indirect_recursion() {
+        let g = Grammar::parse("<A> := <B> a or b\n<B> := <A> c or d\n").unwrap();
+        assert!(g.has_left_recursion().unwrap().is_some());
+
+        let eliminated = g.eliminate_left_recursion();
+        assert_eq!(eliminated.has_left_recursion().unwrap(), None);
+    }
+
+    #[test]
+    fn eliminate_left_recursion_removes_nullable_mediated_recursion() {
+        // <A> reaches itself only through the nullable <X> in front of it,
+        // and <X> can also derive real content (`a`), not just EmptyString -
+        // the case that slips past a literal first-symbol comparison.
+        let g = Grammar::parse("<A> := <X> <A> or y\n<X> := a or EmptyString\n").unwrap();
+        assert!(g.has_left_recursion().unwrap().is_some());
+
+        let eliminated = g.eliminate_left_recursion();
+        assert_eq!(eliminated.has_left_recursion().unwrap(), None);
+    }
+
+    #[test]
+    fn follow_sets_reports_undefined_symbol_instead_of_panicking() {
+        let g = Grammar::parse("<A> := <B> c\n").unwrap();
+        assert!(matches!(g.follow_sets(), Err(GrammarError::UndefinedSymbol(_))));
+    }
+
+    #[test]
+    fn eliminate_left_recursion_does_not_grow_rules_unboundedly() {
+        // Nested nullable-mediated recursion (chunk0-3) used to make this
+        // grammar's elimination clone an ever-growing chain of tail rules
+        // that never converged. With forced-non-empty variants memoized
+        // per non-terminal instead, the number of rules added is bounded
+        // by the input size rather than unbounded.
+        let g = Grammar::parse(
+            "<A> := <X> <A> or y\n<X> := <Y> or EmptyString\n<Y> := a or EmptyString\n",
+        )
+        .unwrap();
+        let eliminated = g.eliminate_left_recursion();
+
+        assert_eq!(eliminated.has_left_recursion().unwrap(), None);
+        assert!(eliminated.rules.len() <= g.rules.len() * 3);
+    }
+
+    #[test]
+    fn has_left_expansion_cycle_agrees_with_has_left_recursion() {
+        // <A> reaches itself through the nullable <X> at the front of its
+        // own production, not through a literal leading self-reference.
+        let g = Grammar::parse("<A> := <X> <A> or y\n<X> := EmptyString\n").unwrap();
+        assert!(g.has_left_recursion().unwrap().is_some());
+        assert!(g.to_nfa().has_left_expansion_cycle());
+    }
 }